@@ -8,7 +8,12 @@
 // except according to those terms.
 
 //! Final-sigma-correct lowercasing iterator adapter for iterators
-//! over `char`. Turkish/Azeri `'I'` handled optionally.
+//! over `char`, with optional Turkish/Azeri, Lithuanian, and Greek
+//! tailoring selected via [`Language`]. Also provides an
+//! uppercasing iterator adapter with the same Turkish/Azeri option,
+//! a case-folding iterator adapter for case-insensitive comparison,
+//! and a word-titlecasing iterator adapter configurable via
+//! [`TitlecaseOptions`].
 
 #![no_std]
 
@@ -16,8 +21,62 @@ extern crate alloc;
 
 use alloc::collections::VecDeque;
 use core::char::ToLowercase;
+use core::char::ToUppercase;
+
+/// Selects which of Unicode's language-specific `SpecialCasing.txt`
+/// lowercasing rules [`IterLowercase::to_lowercase`] should apply,
+/// on top of the unconditional final-sigma rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    /// No language-specific tailoring. This is the default.
+    #[default]
+    Root,
+    /// Turkish/Azeri: `'I'` lowercases to `'ı'` (dotless) instead
+    /// of `'i'`, and `'İ'` lowercases to plain `'i'` instead of
+    /// `"i\u{0307}"` (`'i'` plus a combining dot above).
+    TurkishAzeri,
+    /// Lithuanian: a soft-dotted `'I'`, `'J'`, or `'Į'` keeps its dot
+    /// by gaining a `U+0307 COMBINING DOT ABOVE` when it's followed
+    /// by another `Above`-class combining mark before the next
+    /// starter. `'Ì'`, `'Í'`, and `'Ĩ'` unconditionally decompose to
+    /// `'i'` plus the combining dot above and their original
+    /// combining accent.
+    Lithuanian,
+    /// Greek: currently behaves like `Root`. CLDR's diacritic-
+    /// dropping rule for Greek applies to *uppercasing*, not
+    /// lowercasing, so it has no effect here.
+    Greek,
+}
+
+// Upper-case letters that keep a combining dot above when
+// lowercased in Lithuanian, conditionally on what follows, per
+// SpecialCasing.txt.
+const LITHUANIAN_SOFT_DOTTED: &[char] = &['I', 'J', 'Į'];
+
+// Upper-case soft-dotted letters with a precomposed accent, whose
+// `lt` SpecialCasing mapping is unconditional (unlike the letters
+// in `LITHUANIAN_SOFT_DOTTED` above) and always decomposes to a
+// bare `'i'`, the combining dot above, and the letter's own
+// combining accent.
+const LITHUANIAN_PRECOMPOSED_SOFT_DOTTED: &[(char, char)] = &[
+    ('Ì', '\u{0300}'), // COMBINING GRAVE ACCENT
+    ('Í', '\u{0301}'), // COMBINING ACUTE ACCENT
+    ('Ĩ', '\u{0303}'), // COMBINING TILDE
+];
+
+const COMBINING_DOT_ABOVE: char = '\u{0307}';
+
+const CANONICAL_COMBINING_CLASS_ABOVE: u8 = 230;
 
 /// An iterator adapter yielding lower-case `char`s.
+///
+/// When the delegate iterator is a `DoubleEndedIterator`, this
+/// adapter implements `DoubleEndedIterator`, too, so that it can be
+/// `.rev()`-ed. The `next_back` side maintains its own mirror image
+/// of the final-sigma state, so the primary supported use of
+/// backward iteration is consuming the whole adapter from the back,
+/// e.g. via `.to_lowercase(Language::Root).rev()`; interleaving `next()` and
+/// `next_back()` calls on the same adapter is not supported.
 #[derive(Debug)]
 pub struct Lowercase<I> {
     delegate: I,
@@ -25,9 +84,24 @@ pub struct Lowercase<I> {
     sigma_trail: Option<char>,
     lower: ToLowercase,
     prev_cased: bool,
-    tr_az: bool,
+    language: Language,
     cased: icu_properties::CodePointSetDataBorrowed<'static>,
     case_ignorable: icu_properties::CodePointSetDataBorrowed<'static>,
+    combining_class: icu_properties::CodePointMapDataBorrowed<
+        'static,
+        icu_properties::props::CanonicalCombiningClass,
+    >,
+    // State mirroring the above but for `next_back`: `sigma_lead`
+    // and `sigma_leading_case_ignorables` hold characters found
+    // while scanning backwards (i.e. earlier in the iterator) past
+    // a `'Σ'` to determine whether it's preceded by a cased
+    // character; `back_expansion` holds the not-yet-yielded tail of
+    // a multi-`char` lowercase expansion, drained back to front so
+    // that the expansion itself comes out in reverse order.
+    back_expansion: VecDeque<char>,
+    sigma_leading_case_ignorables: VecDeque<char>,
+    sigma_lead: Option<char>,
+    next_is_cased: bool,
 }
 
 impl<I: Iterator<Item = char>> Iterator for Lowercase<I> {
@@ -44,15 +118,50 @@ impl<I: Iterator<Item = char>> Iterator for Lowercase<I> {
         let c = if let Some(c) = self.sigma_trail {
             self.sigma_trail = None;
             c
-        } else if let Some(c) = self.delegate.next() {
-            c
         } else {
-            return None;
+            self.delegate.next()?
         };
-        if self.tr_az && c == 'I' {
+        if self.language == Language::TurkishAzeri && c == 'I' {
             self.prev_cased = true;
             return Some('ı');
         }
+        if self.language == Language::TurkishAzeri && c == 'İ' {
+            self.prev_cased = true;
+            return Some('i');
+        }
+        if self.language == Language::Lithuanian {
+            if let Some(&(_, accent)) = LITHUANIAN_PRECOMPOSED_SOFT_DOTTED
+                .iter()
+                .find(|&&(letter, _)| letter == c)
+            {
+                self.prev_cased = true;
+                self.sigma_trailing_case_ignorables
+                    .push_back(COMBINING_DOT_ABOVE);
+                self.sigma_trailing_case_ignorables.push_back(accent);
+                return Some('i');
+            }
+        }
+        if self.language == Language::Lithuanian && LITHUANIAN_SOFT_DOTTED.contains(&c) {
+            let mut above = false;
+            for t in self.delegate.by_ref() {
+                let ccc = self.combining_class.get(t).to_icu4c_value();
+                if ccc == 0 {
+                    self.sigma_trail = Some(t);
+                    break;
+                }
+                if ccc == CANONICAL_COMBINING_CLASS_ABOVE {
+                    above = true;
+                }
+                self.sigma_trailing_case_ignorables.push_back(t);
+            }
+            if above {
+                self.sigma_trailing_case_ignorables
+                    .push_front(COMBINING_DOT_ABOVE);
+            }
+            self.prev_cased = true;
+            self.lower = c.to_lowercase();
+            return self.lower.next();
+        }
         if self.cased.contains(c) {
             if c == 'Σ' && self.prev_cased {
                 loop {
@@ -80,20 +189,86 @@ impl<I: Iterator<Item = char>> Iterator for Lowercase<I> {
     }
 }
 
+impl<I: Iterator<Item = char> + DoubleEndedIterator> DoubleEndedIterator for Lowercase<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        if let Some(c) = self.back_expansion.pop_back() {
+            return Some(c);
+        }
+        if let Some(c) = self.sigma_leading_case_ignorables.pop_front() {
+            return Some(c);
+        }
+        let c = if let Some(c) = self.sigma_lead.take() {
+            c
+        } else {
+            self.delegate.next_back()?
+        };
+        // Only the Turkish/Azeri and final-sigma rules are
+        // supported in reverse; `Language::Lithuanian` and
+        // `Language::Greek` are forward-only for now, since they
+        // need to look in the opposite direction from the one
+        // `next_back` already looks in for the sigma rule.
+        if self.language == Language::TurkishAzeri && c == 'I' {
+            self.next_is_cased = true;
+            return Some('ı');
+        }
+        if self.language == Language::TurkishAzeri && c == 'İ' {
+            self.next_is_cased = true;
+            return Some('i');
+        }
+        if self.cased.contains(c) {
+            if c == 'Σ' && !self.next_is_cased {
+                loop {
+                    if let Some(p) = self.delegate.next_back() {
+                        if self.case_ignorable.contains(p) {
+                            self.sigma_leading_case_ignorables.push_back(p);
+                            continue;
+                        }
+                        self.sigma_lead = Some(p);
+                        if self.cased.contains(p) {
+                            return Some('ς');
+                        }
+                    }
+                    return Some('σ');
+                }
+            }
+            self.next_is_cased = true;
+            self.back_expansion = c.to_lowercase().collect();
+            return self.back_expansion.pop_back();
+        }
+        if self.next_is_cased && !self.case_ignorable.contains(c) {
+            self.next_is_cased = false;
+        }
+        Some(c)
+    }
+}
+
 /// Trait that adds a `to_lowercase` method to iterators
 /// over `char`.
 pub trait IterLowercase<I: Iterator<Item = char>> {
-    /// Returns a lower-casing iterator adapter that
-    /// handles final sigma correctly.
-    ///
-    /// `tr_az` set to `true` results in Turkish/Azeri treatment
-    /// of `'I'`.
-    fn to_lowercase(self, tr_az: bool) -> Lowercase<I>;
+    /// Returns a lower-casing iterator adapter that handles final
+    /// sigma correctly and applies `language`'s `SpecialCasing.txt`
+    /// tailoring.
+    fn to_lowercase(self, language: Language) -> Lowercase<I>;
+
+    /// Deprecated equivalent of `to_lowercase` that only offers the
+    /// Turkish/Azeri tailoring.
+    #[deprecated(note = "use `to_lowercase` with a `Language` instead of a `bool`")]
+    fn to_lowercase_tr_az(self, tr_az: bool) -> Lowercase<I>
+    where
+        Self: Sized,
+    {
+        self.to_lowercase(if tr_az {
+            Language::TurkishAzeri
+        } else {
+            Language::Root
+        })
+    }
 }
 
 impl<I: Iterator<Item = char>> IterLowercase<I> for I {
     #[inline]
-    fn to_lowercase(self, tr_az: bool) -> Lowercase<I> {
+    fn to_lowercase(self, language: Language) -> Lowercase<I> {
         // Create a consumed `ToLowercase`
         let mut lower = '\0'.to_lowercase();
         lower.next();
@@ -102,13 +277,266 @@ impl<I: Iterator<Item = char>> IterLowercase<I> for I {
             delegate: self,
             sigma_trailing_case_ignorables: VecDeque::new(),
             sigma_trail: None,
-            lower: lower,
+            lower,
             prev_cased: false,
-            tr_az: tr_az,
+            language,
             cased: icu_properties::CodePointSetData::new::<icu_properties::props::Cased>(),
             case_ignorable: icu_properties::CodePointSetData::new::<
                 icu_properties::props::CaseIgnorable,
             >(),
+            combining_class: icu_properties::CodePointMapData::<
+                icu_properties::props::CanonicalCombiningClass,
+            >::new(),
+            back_expansion: VecDeque::new(),
+            sigma_leading_case_ignorables: VecDeque::new(),
+            sigma_lead: None,
+            next_is_cased: false,
+        }
+    }
+}
+
+/// An iterator adapter yielding upper-case `char`s.
+#[derive(Debug)]
+pub struct Uppercase<I> {
+    delegate: I,
+    upper: ToUppercase,
+    tr_az: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for Uppercase<I> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if let Some(c) = self.upper.next() {
+            return Some(c);
+        }
+        let c = self.delegate.next()?;
+        if self.tr_az && c == 'i' {
+            return Some('İ');
+        }
+        self.upper = c.to_uppercase();
+        self.upper.next()
+    }
+}
+
+/// Trait that adds a `to_uppercase` method to iterators
+/// over `char`.
+pub trait IterUppercase<I: Iterator<Item = char>> {
+    /// Returns an upper-casing iterator adapter.
+    ///
+    /// `tr_az` set to `true` results in Turkish/Azeri treatment
+    /// of `'i'`, which is mapped to `'İ'` (dotted capital I)
+    /// instead of `'I'`.
+    ///
+    /// Unlike lowercasing, uppercasing does not need a final-sigma
+    /// rule, since the upper case of `'σ'` and `'ς'` is always `'Σ'`.
+    fn to_uppercase(self, tr_az: bool) -> Uppercase<I>;
+}
+
+impl<I: Iterator<Item = char>> IterUppercase<I> for I {
+    #[inline]
+    fn to_uppercase(self, tr_az: bool) -> Uppercase<I> {
+        // Create a consumed `ToUppercase`
+        let mut upper = '\0'.to_uppercase();
+        upper.next();
+
+        Uppercase {
+            delegate: self,
+            upper,
+            tr_az,
+        }
+    }
+}
+
+/// An iterator adapter yielding the Unicode *full case fold* of
+/// a `char` iterator, for case-insensitive comparison.
+#[derive(Debug)]
+pub struct Casefold<I> {
+    delegate: I,
+    pending: VecDeque<char>,
+    turkic: bool,
+    case_mapper: icu_casemap::CaseMapperBorrowed<'static>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for Casefold<I> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if let Some(c) = self.pending.pop_front() {
+            return Some(c);
+        }
+        let c = self.delegate.next()?;
+        let mut buf = [0u8; 4];
+        let src = c.encode_utf8(&mut buf);
+        let folded = if self.turkic {
+            self.case_mapper.fold_turkic_string(src)
+        } else {
+            self.case_mapper.fold_string(src)
+        };
+        let mut chars = folded.chars();
+        let first = chars.next().unwrap_or(c);
+        self.pending.extend(chars);
+        Some(first)
+    }
+}
+
+/// Trait that adds a `case_fold` method to iterators over `char`.
+pub trait IterCaseFold<I: Iterator<Item = char>> {
+    /// Returns a case-folding iterator adapter suitable for
+    /// case-insensitive comparison, e.g.
+    /// `a.chars().case_fold(false).eq(b.chars().case_fold(false))`.
+    ///
+    /// `turkic` set to `true` results in the Turkic folding of
+    /// `'I'` to `'ı'` and `'İ'` to `'i'` instead of the default
+    /// folding, driven by the same `icu_casemap` data as the
+    /// default folding rather than a hand-picked exception.
+    fn case_fold(self, turkic: bool) -> Casefold<I>;
+}
+
+impl<I: Iterator<Item = char>> IterCaseFold<I> for I {
+    #[inline]
+    fn case_fold(self, turkic: bool) -> Casefold<I> {
+        Casefold {
+            delegate: self,
+            pending: VecDeque::new(),
+            turkic,
+            case_mapper: icu_casemap::CaseMapper::new(),
+        }
+    }
+}
+
+/// Controls whether the leading character of a titlecased word is
+/// adjusted before the title mapping is applied, mirroring ICU's
+/// `TitlecaseOptions::leading_adjustment`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeadingAdjustment {
+    /// Skip forward past leading case-ignorable characters to the
+    /// first cased character before titlecasing it. This is the
+    /// default.
+    #[default]
+    Auto,
+    /// Apply the title mapping to the very first character of the
+    /// word, whether or not it is cased.
+    None,
+    /// Like `Auto`, but without the language-specific leading
+    /// apostrophe exception that `Auto` may apply.
+    ToCased,
+}
+
+/// Controls what happens to the rest of a word after its titlecased
+/// leading character, mirroring ICU's
+/// `TitlecaseOptions::trailing_case`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrailingCase {
+    /// Lower-case the remainder of the word, using the same
+    /// final-sigma-correct mapping as [`IterLowercase`]. This is
+    /// the default.
+    #[default]
+    Lower,
+    /// Leave the remainder of the word as-is.
+    Unchanged,
+}
+
+/// Options for [`IterTitlecase::titlecase`], analogous to ICU's
+/// `TitlecaseOptions`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TitlecaseOptions {
+    /// See [`LeadingAdjustment`].
+    pub leading_adjustment: LeadingAdjustment,
+    /// See [`TrailingCase`].
+    pub trailing_case: TrailingCase,
+}
+
+fn to_icu_titlecase_options(options: TitlecaseOptions) -> icu_casemap::options::TitlecaseOptions {
+    let mut icu_options = icu_casemap::options::TitlecaseOptions::default();
+    icu_options.leading_adjustment = Some(match options.leading_adjustment {
+        LeadingAdjustment::Auto => icu_casemap::options::LeadingAdjustment::Auto,
+        LeadingAdjustment::None => icu_casemap::options::LeadingAdjustment::None,
+        LeadingAdjustment::ToCased => icu_casemap::options::LeadingAdjustment::ToCased,
+    });
+    icu_options.trailing_case = Some(match options.trailing_case {
+        TrailingCase::Lower => icu_casemap::options::TrailingCase::Lower,
+        TrailingCase::Unchanged => icu_casemap::options::TrailingCase::Unchanged,
+    });
+    icu_options
+}
+
+fn titlecase_string(input: &str, options: TitlecaseOptions) -> alloc::string::String {
+    let segmenter = icu_segmenter::WordSegmenter::new_auto(
+        icu_segmenter::options::WordBreakInvariantOptions::default(),
+    );
+    let titlecase_mapper = icu_casemap::TitlecaseMapper::new();
+    let icu_options = to_icu_titlecase_options(options);
+    let locale = icu_locale_core::LanguageIdentifier::UNKNOWN;
+
+    let mut result = alloc::string::String::with_capacity(input.len());
+    // A non-word-like segment (space, punctuation, ...) isn't titlecased
+    // on its own; it's folded into the titlecasing call for the word
+    // that follows it, so that e.g. a leading apostrophe shares that
+    // word's `LeadingAdjustment` instead of being titlecased as if it
+    // were its own one-character word.
+    let mut unit_start = 0;
+    let mut end = 0;
+    for (boundary, word_type) in segmenter.segment_str(input).iter_with_word_type().skip(1) {
+        end = boundary;
+        if word_type.is_word_like() {
+            result.push_str(&titlecase_mapper.titlecase_segment_to_string(
+                &input[unit_start..end],
+                &locale,
+                icu_options,
+            ));
+            unit_start = end;
+        }
+    }
+    result.push_str(&input[unit_start..end]);
+    result
+}
+
+/// An iterator adapter that titlecases its input at word
+/// boundaries, using [`icu_segmenter`]'s word segmenter to find
+/// them.
+///
+/// Unlike the other adapters in this crate, word segmentation
+/// needs the whole input available at once, so `Titlecase` buffers
+/// the entire delegate iterator into a `String` the first time it
+/// is polled, rather than processing `char`s one at a time.
+#[derive(Debug)]
+pub struct Titlecase<I> {
+    delegate: Option<I>,
+    options: TitlecaseOptions,
+    output: VecDeque<char>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for Titlecase<I> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if let Some(delegate) = self.delegate.take() {
+            let input: alloc::string::String = delegate.collect();
+            self.output = titlecase_string(&input, self.options).chars().collect();
+        }
+        self.output.pop_front()
+    }
+}
+
+/// Trait that adds a `titlecase` method to iterators over `char`.
+pub trait IterTitlecase<I: Iterator<Item = char>> {
+    /// Returns a titlecasing iterator adapter using `options` to
+    /// control the treatment of each word's leading character and
+    /// the rest of the word.
+    fn titlecase(self, options: TitlecaseOptions) -> Titlecase<I>;
+}
+
+impl<I: Iterator<Item = char>> IterTitlecase<I> for I {
+    #[inline]
+    fn titlecase(self, options: TitlecaseOptions) -> Titlecase<I> {
+        Titlecase {
+            delegate: Some(self),
+            options,
+            output: VecDeque::new(),
         }
     }
 }
@@ -119,7 +547,7 @@ mod tests {
     use alloc::string::String;
     fn check(s: &str) {
         assert_eq!(
-            s.chars().to_lowercase(false).collect::<String>(),
+            s.chars().to_lowercase(Language::Root).collect::<String>(),
             s.to_lowercase()
         );
     }
@@ -160,11 +588,323 @@ mod tests {
 
     #[test]
     fn test_i() {
-        assert_eq!("ΣIΣ".chars().to_lowercase(true).collect::<String>(), "σıς");
+        assert_eq!(
+            "ΣIΣ"
+                .chars()
+                .to_lowercase(Language::TurkishAzeri)
+                .collect::<String>(),
+            "σıς"
+        );
+    }
+
+    #[test]
+    fn test_dotted_capital_i_tr_az() {
+        assert_eq!(
+            "İstanbul"
+                .chars()
+                .to_lowercase(Language::TurkishAzeri)
+                .collect::<String>(),
+            "istanbul"
+        );
+        assert_eq!(
+            "İstanbul"
+                .chars()
+                .to_lowercase(Language::TurkishAzeri)
+                .rev()
+                .collect::<String>(),
+            "istanbul".chars().rev().collect::<String>()
+        );
+        // Outside of `TurkishAzeri`, `'İ'` gets the generic
+        // full-casing expansion to `'i'` plus a combining dot
+        // above.
+        assert_eq!(
+            "İ".chars().to_lowercase(Language::Root).collect::<String>(),
+            "i\u{307}"
+        );
     }
 
     #[test]
     fn test_uncased() {
         check("猪猪");
     }
+
+    #[test]
+    fn test_lithuanian_dot_above() {
+        assert_eq!(
+            "I\u{0300}"
+                .chars()
+                .to_lowercase(Language::Lithuanian)
+                .collect::<String>(),
+            "i\u{0307}\u{0300}"
+        );
+        // No following above-class mark: no inserted dot.
+        assert_eq!(
+            "I".chars()
+                .to_lowercase(Language::Lithuanian)
+                .collect::<String>(),
+            "i"
+        );
+        // Root tailoring never inserts the dot.
+        assert_eq!(
+            "I\u{0300}"
+                .chars()
+                .to_lowercase(Language::Root)
+                .collect::<String>(),
+            "i\u{0300}"
+        );
+    }
+
+    #[test]
+    fn test_lithuanian_precomposed_soft_dotted() {
+        // These three precomposed letters decompose unconditionally,
+        // unlike the bare `I`/`J`/`Į` above, which only gain the dot
+        // when followed by another `Above`-class combining mark.
+        assert_eq!(
+            "Ì".chars()
+                .to_lowercase(Language::Lithuanian)
+                .collect::<String>(),
+            "i\u{0307}\u{0300}"
+        );
+        assert_eq!(
+            "Í".chars()
+                .to_lowercase(Language::Lithuanian)
+                .collect::<String>(),
+            "i\u{0307}\u{0301}"
+        );
+        assert_eq!(
+            "Ĩ".chars()
+                .to_lowercase(Language::Lithuanian)
+                .collect::<String>(),
+            "i\u{0307}\u{0303}"
+        );
+        // Root tailoring uses the ordinary Unicode default mapping.
+        assert_eq!(
+            "Ì".chars().to_lowercase(Language::Root).collect::<String>(),
+            "ì"
+        );
+    }
+
+    #[test]
+    fn test_greek_is_root_like() {
+        // CLDR's diacritic-dropping rule for Greek applies to
+        // uppercasing, not lowercasing, so `Language::Greek`
+        // lowercases the same as `Language::Root`.
+        assert_eq!(
+            "Ά\u{0301}"
+                .chars()
+                .to_lowercase(Language::Greek)
+                .collect::<String>(),
+            "ά\u{0301}"
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_to_lowercase_tr_az_wrapper() {
+        assert_eq!(
+            "ΣIΣ".chars().to_lowercase_tr_az(true).collect::<String>(),
+            "σıς"
+        );
+        assert_eq!(
+            "I".chars().to_lowercase_tr_az(false).collect::<String>(),
+            "i"
+        );
+    }
+
+    fn check_rev(s: &str) {
+        let forward = s.chars().to_lowercase(Language::Root).collect::<String>();
+        let backward = s
+            .chars()
+            .to_lowercase(Language::Root)
+            .rev()
+            .collect::<String>();
+        assert_eq!(
+            backward,
+            forward.chars().rev().collect::<String>(),
+            "mismatch for {:?}",
+            s
+        );
+    }
+
+    #[test]
+    fn test_sigma_rev() {
+        for s in [
+            "Σ",
+            "Σ\u{0301}",
+            "ΣΣ",
+            "Σ\u{0301}Σ\u{0301}",
+            "ΣΣ ",
+            "Σ\u{0301}Σ\u{0301} ",
+            " Σ",
+            " Σ\u{0301}",
+            "ΣΣ-",
+            "Σ\u{0301}Σ\u{0301}-",
+            "-Σ",
+            "-Σ\u{0301}",
+            "ΣΣ猪",
+            "Σ\u{0301}Σ\u{0301}猪",
+            "猪Σ",
+            "猪Σ\u{0301}",
+            "ΣΣB",
+            "Σ\u{0301}Σ\u{0301}B",
+            "BΣ",
+            "BΣ\u{0301}",
+            "ΣΣΔ",
+            "Σ\u{0301}Σ\u{0301}Δ",
+            "ΔΣ",
+            "ΔΣ\u{0301}",
+        ]
+        .iter()
+        {
+            check_rev(s);
+        }
+    }
+
+    #[test]
+    fn test_expansion_rev() {
+        check_rev("Stra\u{DF}e İstanbul");
+    }
+
+    #[test]
+    fn test_titlecase_basic() {
+        assert_eq!(
+            "the QUICK fox"
+                .chars()
+                .titlecase(TitlecaseOptions::default())
+                .collect::<String>(),
+            "The Quick Fox"
+        );
+    }
+
+    #[test]
+    fn test_titlecase_leading_none() {
+        let options = TitlecaseOptions {
+            leading_adjustment: LeadingAdjustment::None,
+            trailing_case: TrailingCase::Lower,
+        };
+        assert_eq!(
+            "'cause".chars().titlecase(options).collect::<String>(),
+            "'cause"
+        );
+    }
+
+    #[test]
+    fn test_titlecase_leading_to_cased() {
+        // `Auto` adjusts to the first letter/number/symbol/private-use
+        // character, so the leading digits of "49ers" already count
+        // and nothing is titlecased. `ToCased` adjusts to the first
+        // *cased* character instead, which is the 'e', so the two
+        // options visibly diverge here.
+        let auto = TitlecaseOptions {
+            leading_adjustment: LeadingAdjustment::Auto,
+            trailing_case: TrailingCase::Lower,
+        };
+        let to_cased = TitlecaseOptions {
+            leading_adjustment: LeadingAdjustment::ToCased,
+            trailing_case: TrailingCase::Lower,
+        };
+        assert_eq!(
+            "49ers".chars().titlecase(auto).collect::<String>(),
+            "49ers"
+        );
+        assert_eq!(
+            "49ers".chars().titlecase(to_cased).collect::<String>(),
+            "49Ers"
+        );
+    }
+
+    #[test]
+    fn test_titlecase_trailing_unchanged() {
+        let options = TitlecaseOptions {
+            leading_adjustment: LeadingAdjustment::Auto,
+            trailing_case: TrailingCase::Unchanged,
+        };
+        assert_eq!(
+            "QUICK FOX".chars().titlecase(options).collect::<String>(),
+            "QUICK FOX"
+        );
+    }
+
+    #[test]
+    fn test_titlecase_digraph() {
+        // U+01F3 LATIN SMALL LETTER DZ titlecases to U+01F2 LATIN
+        // CAPITAL LETTER D WITH SMALL LETTER Z, not to the
+        // all-caps U+01F1.
+        assert_eq!(
+            "\u{1F3}op"
+                .chars()
+                .titlecase(TitlecaseOptions::default())
+                .collect::<String>(),
+            "\u{1F2}op"
+        );
+    }
+
+    #[test]
+    fn test_titlecase_sigma() {
+        assert_eq!(
+            "ΣΣ ΣΣ"
+                .chars()
+                .titlecase(TitlecaseOptions::default())
+                .collect::<String>(),
+            "Σς Σς"
+        );
+    }
+
+    fn check_upper(s: &str) {
+        assert_eq!(
+            s.chars().to_uppercase(false).collect::<String>(),
+            s.to_uppercase()
+        );
+    }
+
+    #[test]
+    fn test_uppercase_expansion() {
+        check_upper("ß");
+        check_upper("ﬁ");
+    }
+
+    #[test]
+    fn test_uppercase_i() {
+        assert_eq!("i".chars().to_uppercase(true).collect::<String>(), "İ");
+        assert_eq!("i".chars().to_uppercase(false).collect::<String>(), "I");
+    }
+
+    #[test]
+    fn test_uppercase_uncased() {
+        check_upper("猪猪");
+    }
+
+    #[test]
+    fn test_case_fold_sigma() {
+        let folded = ["Σ", "σ", "ς"]
+            .iter()
+            .map(|s| s.chars().case_fold(false).collect::<String>())
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(folded[0], folded[1]);
+        assert_eq!(folded[1], folded[2]);
+    }
+
+    #[test]
+    fn test_case_fold_expansion() {
+        assert_eq!("ß".chars().case_fold(false).collect::<String>(), "ss");
+        assert_eq!("ﬁ".chars().case_fold(false).collect::<String>(), "fi");
+    }
+
+    #[test]
+    fn test_case_fold_dotted_i() {
+        assert_eq!(
+            "İ".chars().case_fold(false).collect::<String>(),
+            "i\u{0307}"
+        );
+        assert_eq!("İ".chars().case_fold(true).collect::<String>(), "i");
+        assert_eq!("I".chars().case_fold(true).collect::<String>(), "ı");
+    }
+
+    #[test]
+    fn test_case_insensitive_eq() {
+        assert!("STRASSE"
+            .chars()
+            .case_fold(false)
+            .eq("straße".chars().case_fold(false)));
+    }
 }